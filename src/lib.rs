@@ -9,21 +9,47 @@
 //!
 //! Original proposal found here: https://arxiv.org/html/0901.4016
 //!
+//! # no_std
+//!
+//! This crate only needs `core` and `alloc` (for `String`), and is
+//! `#![no_std]` when built with `default-features = false`. The
+//! `Ipv4Addr`/`Ipv6Addr` impls and the `std::error::Error` impl for
+//! `QuintError` require `std` and are gated behind the `std` feature, which
+//! is on by default.
+//!
 //! # Example
+//!
+//! This example needs the (default-on) `std` feature, since `Ipv4Addr` is
+//! a `std` type.
 //! ```
+//! # #[cfg(feature = "std")]
+//! # fn doctest() {
 //! use proquint::Quintable;
 //! use std::net::Ipv4Addr;
 //!
 //! let home = Ipv4Addr::new(127, 0, 0, 1);
 //! assert_eq!(home.to_quint(), "lusab-babad");
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn doctest() {}
+//! # doctest();
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate core;
+extern crate alloc;
+
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt::{Display, Formatter};
-use std::fmt;
-use std::ops::{ShlAssign, AddAssign};
+use core::fmt::{self, Display, Formatter};
+use core::ops::{ShlAssign, AddAssign};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[macro_use]
 extern crate quickcheck;
 
@@ -32,6 +58,7 @@ pub enum QuintError {
     InputTooSmall,
     InputTooLarge,
     InputInvalid,
+    ChecksumMismatch,
 }
 
 impl Display for QuintError {
@@ -40,17 +67,20 @@ impl Display for QuintError {
             QuintError::InputTooLarge => "proquint was too large",
             QuintError::InputTooSmall => "expected larger proquint",
             QuintError::InputInvalid => "input was not a valid proquint",
+            QuintError::ChecksumMismatch => "proquint checksum did not match",
         };
         write!(f, "{}", out)
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for QuintError {
     fn description(&self) -> &str {
         match *self {
             QuintError::InputTooLarge => "proquint was too large",
             QuintError::InputTooSmall => "expected larger proquint",
             QuintError::InputInvalid => "input was not a valid proquint",
+            QuintError::ChecksumMismatch => "proquint checksum did not match",
         }
     }
 
@@ -78,6 +108,11 @@ pub trait Quintable
 
     /// Converts a proquint string to this type
     ///
+    /// Unknown characters (including stray punctuation) are silently
+    /// skipped, so a mistyped proquint can still decode to a value instead
+    /// of an error. Use [`from_quint_strict`](#tymethod.from_quint_strict)
+    /// when transcription errors need to be caught.
+    ///
     /// # Example
     /// ```
     /// use proquint::Quintable;
@@ -85,6 +120,79 @@ pub trait Quintable
     /// assert_eq!(u32::from_quint("rotab-vinat").unwrap(), 3141592653u32);
     /// ```
     fn from_quint(&str) -> Result<Self, QuintError>;
+
+    /// Converts a proquint string to this type, rejecting anything that
+    /// isn't a well-formed proquint.
+    ///
+    /// Unlike [`from_quint`](#tymethod.from_quint), every character must be
+    /// a known consonant or vowel, and they must alternate in the
+    /// consonant-vowel-consonant-vowel-consonant pattern with
+    /// `-` only between five-letter groups. This catches the kind of
+    /// transcription errors (swapped letters, dropped separators) that the
+    /// lenient decoder would otherwise accept silently.
+    ///
+    /// # Example
+    /// ```
+    /// use proquint::Quintable;
+    ///
+    /// assert_eq!(u32::from_quint_strict("rotab-vinat").unwrap(), 3141592653u32);
+    /// assert!(u32::from_quint_strict("rotab!vinat").is_err());
+    /// ```
+    fn from_quint_strict(&str) -> Result<Self, QuintError>;
+
+    /// Converts this type into a proquint `String` with a trailing checksum
+    /// quint appended, for detecting transcription errors.
+    ///
+    /// The checksum is the wrapping sum of every 16-bit group in the
+    /// unchecked proquint, mod 65536, encoded as one more quint and joined
+    /// with [`SEPARATOR`](constant.SEPARATOR.html).
+    ///
+    /// # Example
+    /// ```
+    /// use proquint::Quintable;
+    ///
+    /// let foo: u32 = 12;
+    /// let checked = foo.to_quint_checked();
+    /// assert_eq!(u32::from_quint_checked(&checked).unwrap(), foo);
+    /// ```
+    fn to_quint_checked(&self) -> String {
+        let body = self.to_quint();
+        let checksum = quint_checksum(&body);
+
+        let mut out = body;
+        out.push(SEPARATOR);
+        out.push_str(&checksum.to_quint());
+
+        out
+    }
+
+    /// Converts a checksummed proquint string (as produced by
+    /// [`to_quint_checked`](#method.to_quint_checked)) back to this type.
+    ///
+    /// Recomputes the checksum over the body and compares it to the
+    /// trailing quint, returning `QuintError::ChecksumMismatch` if they
+    /// disagree so that a transcription error can be distinguished from a
+    /// merely unexpected value.
+    fn from_quint_checked(quint: &str) -> Result<Self, QuintError> {
+        let split_at = quint.rfind(SEPARATOR).ok_or(QuintError::InputInvalid)?;
+        let (body, checksum_quint) = quint.split_at(split_at);
+        let checksum_quint = &checksum_quint[1..];
+
+        let checksum: u16 = u16::from_quint(checksum_quint)?;
+        if checksum != quint_checksum(body) {
+            return Err(QuintError::ChecksumMismatch);
+        }
+
+        Self::from_quint(body)
+    }
+}
+
+/// Sums the 16-bit groups of an (unchecked) proquint string, wrapping mod
+/// 65536. Used by [`Quintable::to_quint_checked`](trait.Quintable.html#method.to_quint_checked)
+/// and [`Quintable::from_quint_checked`](trait.Quintable.html#method.from_quint_checked).
+fn quint_checksum(quint: &str) -> u16 {
+    quint.split(SEPARATOR)
+        .fold(0u16, |acc, group| acc.wrapping_add(u16::from_quint(group).unwrap_or(0)))
 }
 
 macro_rules! decons {
@@ -170,6 +278,92 @@ pub fn from_quint<T>(quint: &str) -> (T, usize)
     (res, bitcounter)
 }
 
+/// Generic function for strictly converting a proquint string to the given
+/// type.
+///
+/// Every character must be a known consonant or vowel, alternating in the
+/// consonant-vowel-consonant-vowel-consonant pattern, with `-` allowed only
+/// between five-letter groups. Returns `QuintError::InputInvalid` on the
+/// first character that breaks those rules; otherwise behaves like
+/// [`from_quint`](fn.from_quint.html), returning the decoded value and the
+/// number of bits decoded.
+pub fn from_quint_strict<T>(quint: &str) -> Result<(T, usize), QuintError>
+    where T: Sized + Default + ShlAssign<isize> + AddAssign<T> + From<u8>
+{
+    let mut bitcounter = 0usize;
+    let mut res: T = T::default();
+    let mut pos_in_group = 0usize;
+    let mut expect_separator = false;
+    let mut dangling_separator = false;
+
+    for c in quint.chars() {
+        if expect_separator {
+            if c != SEPARATOR {
+                return Err(QuintError::InputInvalid);
+            }
+            expect_separator = false;
+            dangling_separator = true;
+            continue;
+        }
+
+        dangling_separator = false;
+
+        let is_consonant_pos = pos_in_group.is_multiple_of(2);
+        let digit: Option<T> = if is_consonant_pos {
+            match c {
+                'b' => Some(T::from(0u8)),
+                'd' => Some(T::from(1u8)),
+                'f' => Some(T::from(2u8)),
+                'g' => Some(T::from(3u8)),
+                'h' => Some(T::from(4u8)),
+                'j' => Some(T::from(5u8)),
+                'k' => Some(T::from(6u8)),
+                'l' => Some(T::from(7u8)),
+                'm' => Some(T::from(8u8)),
+                'n' => Some(T::from(9u8)),
+                'p' => Some(T::from(10u8)),
+                'r' => Some(T::from(11u8)),
+                's' => Some(T::from(12u8)),
+                't' => Some(T::from(13u8)),
+                'v' => Some(T::from(14u8)),
+                'z' => Some(T::from(15u8)),
+                _ => None,
+            }
+        } else {
+            match c {
+                'a' => Some(T::from(0u8)),
+                'i' => Some(T::from(1u8)),
+                'o' => Some(T::from(2u8)),
+                'u' => Some(T::from(3u8)),
+                _ => None,
+            }
+        };
+
+        let digit = match digit {
+            Some(d) => d,
+            None => return Err(QuintError::InputInvalid),
+        };
+
+        if is_consonant_pos {
+            decons!(res, bitcounter, digit);
+        } else {
+            devowel!(res, bitcounter, digit);
+        }
+
+        pos_in_group += 1;
+        if pos_in_group == 5 {
+            pos_in_group = 0;
+            expect_separator = true;
+        }
+    }
+
+    if dangling_separator {
+        return Err(QuintError::InputInvalid);
+    }
+
+    Ok((res, bitcounter))
+}
+
 pub fn unquint_exactly<T>(quint: &str, bits: usize) -> Result<(T, usize), QuintError>
     where T: Sized + Default + ShlAssign<isize> + AddAssign<T> + From<u8>
 {
@@ -238,6 +432,22 @@ macro_rules! impl_from_quint {
     }
 }
 
+macro_rules! impl_from_quint_strict {
+    ($expected_bits:expr) => {
+        fn from_quint_strict(quint: &str) -> Result<Self, QuintError> {
+            let (res, bits) = from_quint_strict(quint)?;
+            if bits == $expected_bits {
+                return Ok(res);
+            }
+            if bits < $expected_bits {
+                return Err(QuintError::InputTooSmall);
+            } else {
+                return Err(QuintError::InputTooLarge);
+            }
+        }
+    }
+}
+
 impl Quintable for u16 {
     fn to_quint(&self) -> String {
         let mut out = String::with_capacity(5);
@@ -255,6 +465,7 @@ impl Quintable for u16 {
     }
 
     impl_from_quint!(16);
+    impl_from_quint_strict!(16);
 }
 
 impl Quintable for u32 {
@@ -271,6 +482,7 @@ impl Quintable for u32 {
     }
 
     impl_from_quint!(32);
+    impl_from_quint_strict!(32);
 }
 
 impl Quintable for u64 {
@@ -293,8 +505,45 @@ impl Quintable for u64 {
     }
 
     impl_from_quint!(64);
+    impl_from_quint_strict!(64);
 }
 
+impl Quintable for u128 {
+    fn to_quint(&self) -> String {
+        let mut out = String::with_capacity(47);
+        let first = ((self & 0xFFFF0000000000000000000000000000) >> 112) as u16;
+        let second = ((self & 0x0000FFFF000000000000000000000000) >> 96) as u16;
+        let third = ((self & 0x00000000FFFF00000000000000000000) >> 80) as u16;
+        let fourth = ((self & 0x000000000000FFFF0000000000000000) >> 64) as u16;
+        let fifth = ((self & 0x0000000000000000FFFF000000000000) >> 48) as u16;
+        let sixth = ((self & 0x00000000000000000000FFFF00000000) >> 32) as u16;
+        let seventh = ((self & 0x000000000000000000000000FFFF0000) >> 16) as u16;
+        let eighth = (self & 0x0000000000000000000000000000FFFF) as u16;
+
+        out.push_str(&first.to_quint());
+        out.push(SEPARATOR);
+        out.push_str(&second.to_quint());
+        out.push(SEPARATOR);
+        out.push_str(&third.to_quint());
+        out.push(SEPARATOR);
+        out.push_str(&fourth.to_quint());
+        out.push(SEPARATOR);
+        out.push_str(&fifth.to_quint());
+        out.push(SEPARATOR);
+        out.push_str(&sixth.to_quint());
+        out.push(SEPARATOR);
+        out.push_str(&seventh.to_quint());
+        out.push(SEPARATOR);
+        out.push_str(&eighth.to_quint());
+
+        out
+    }
+
+    impl_from_quint!(128);
+    impl_from_quint_strict!(128);
+}
+
+#[cfg(feature = "std")]
 impl Quintable for std::net::Ipv4Addr {
     fn to_quint(&self) -> String {
         let octets = self.octets();
@@ -314,8 +563,20 @@ impl Quintable for std::net::Ipv4Addr {
 
         Ok(std::net::Ipv4Addr::new(first as u8, second as u8, third as u8, fourth as u8))
     }
+
+    fn from_quint_strict(quint: &str) -> Result<std::net::Ipv4Addr, QuintError> {
+        let as_int: u32 = u32::from_quint_strict(quint)?;
+
+        let first = as_int >> 24;
+        let second = (as_int & 0x00FF0000) >> 16;
+        let third = (as_int & 0x0000FF00) >> 8;
+        let fourth = as_int & 0x000000FF;
+
+        Ok(std::net::Ipv4Addr::new(first as u8, second as u8, third as u8, fourth as u8))
+    }
 }
 
+#[cfg(feature = "std")]
 impl Quintable for std::net::Ipv6Addr {
     fn to_quint(&self) -> String {
         let segments: [u16; 8] = self.segments();
@@ -343,19 +604,271 @@ impl Quintable for std::net::Ipv6Addr {
 
         Ok(std::net::Ipv6Addr::new(first, second, third, fourth, fifth, sixth, seventh, eighth))
     }
+
+    fn from_quint_strict(quint: &str) -> Result<Self, QuintError> {
+        let groups: Vec<&str> = quint.split(SEPARATOR).collect();
+        if groups.len() != 8 {
+            return Err(QuintError::InputInvalid);
+        }
+
+        let mut segments = [0u16; 8];
+        for (i, group) in groups.iter().enumerate() {
+            segments[i] = u16::from_quint_strict(group)?;
+        }
+
+        Ok(std::net::Ipv6Addr::new(segments[0], segments[1], segments[2], segments[3],
+                                    segments[4], segments[5], segments[6], segments[7]))
+    }
+}
+
+/// Trait for values that can be fallibly converted to and from proquints.
+///
+/// [`Quintable`](trait.Quintable.html) assumes `to_quint` can never fail,
+/// which doesn't hold for `Vec<u8>`: not every byte buffer has a length
+/// that packs evenly into the 16-bit groups a proquint is made of. This
+/// trait reports that failure through `QuintError` rather than panicking,
+/// the way the rest of the crate reports failure.
+pub trait TryQuintable
+    where Self: Sized
+{
+    /// Converts this value into a proquint `String`, or fails if it can't be
+    /// represented as one.
+    fn try_to_quint(&self) -> Result<String, QuintError>;
+
+    /// Converts a proquint string to this type.
+    ///
+    /// Unknown characters (including stray punctuation) are silently
+    /// skipped, so a mistyped proquint can still decode to a value instead
+    /// of an error. Use
+    /// [`try_from_quint_strict`](#tymethod.try_from_quint_strict) when
+    /// transcription errors need to be caught.
+    fn try_from_quint(quint: &str) -> Result<Self, QuintError>;
+
+    /// Converts a proquint string to this type, rejecting anything that
+    /// isn't a well-formed proquint. See
+    /// [`Quintable::from_quint_strict`](trait.Quintable.html#tymethod.from_quint_strict)
+    /// for the alternation rules this enforces.
+    fn try_from_quint_strict(quint: &str) -> Result<Self, QuintError>;
 }
 
-#[cfg(test)]
+/// Converts an arbitrary byte buffer to and from proquints.
+///
+/// Bytes are chunked into 16-bit groups, each encoded as a single quint, and
+/// the groups are joined with [`SEPARATOR`](constant.SEPARATOR.html) just
+/// like the multi-word integer impls. Every group needs a full two bytes, so
+/// [`try_to_quint`](trait.TryQuintable.html#tymethod.try_to_quint) returns
+/// `QuintError::InputInvalid` for an odd-length buffer rather than padding
+/// it and losing the original length on decode. This is why `Vec<u8>`
+/// implements [`TryQuintable`](trait.TryQuintable.html) instead of
+/// [`Quintable`](trait.Quintable.html): `Quintable::to_quint` is infallible
+/// by signature, and a buffer of arbitrary length can't honor that.
+///
+/// There's no `impl TryQuintable for &[u8]`/`[u8]` alongside this one:
+/// `try_from_quint` returns `Self` by value with no lifetime to tie a
+/// borrowed slice to, so only the owned `Vec<u8>` can implement the round
+/// trip.
+///
+/// # Example
+/// ```
+/// use proquint::TryQuintable;
+///
+/// let bytes: Vec<u8> = vec![0xCA, 0xFE, 0xBA, 0xBE];
+/// let quint = bytes.try_to_quint().unwrap();
+/// assert_eq!(Vec::<u8>::try_from_quint(&quint).unwrap(), bytes);
+/// ```
+impl TryQuintable for Vec<u8> {
+    fn try_to_quint(&self) -> Result<String, QuintError> {
+        if !self.len().is_multiple_of(2) {
+            return Err(QuintError::InputInvalid);
+        }
+
+        let mut out = String::with_capacity((self.len() / 2) * 6);
+
+        let mut first = true;
+        for chunk in self.chunks(2) {
+            if !first {
+                out.push(SEPARATOR);
+            }
+            first = false;
+
+            let word: u16 = ((chunk[0] as u16) << 8) | chunk[1] as u16;
+            out.push_str(&word.to_quint());
+        }
+
+        Ok(out)
+    }
+
+    fn try_from_quint(quint: &str) -> Result<Self, QuintError> {
+        if quint.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut bytes = Vec::new();
+        for group in quint.split(SEPARATOR) {
+            let word: u16 = u16::from_quint(group)?;
+            bytes.push((word >> 8) as u8);
+            bytes.push((word & 0x00FF) as u8);
+        }
+
+        Ok(bytes)
+    }
+
+    fn try_from_quint_strict(quint: &str) -> Result<Self, QuintError> {
+        if quint.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut bytes = Vec::new();
+        for group in quint.split(SEPARATOR) {
+            let word: u16 = u16::from_quint_strict(group)?;
+            bytes.push((word >> 8) as u8);
+            bytes.push((word & 0x00FF) as u8);
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// A configurable proquint codec.
+///
+/// The encoder is layered on top of [`Quintable`](trait.Quintable.html):
+/// `encode` translates the output of `to_quint`, and `decode` translates
+/// back to the default alphabet and calls `from_quint`, so
+/// `ProquintEncoder::default()` gives the same results as `Quintable`.
+/// `Quintable` itself does not go through an encoder; its alphabet and
+/// separator are fixed. Build an encoder directly when a different
+/// separator or consonant/vowel alphabet is needed, for example URL-safe or
+/// space-separated output. The bit packing itself is never reimplemented.
+///
+/// # Example
+/// ```
+/// use proquint::{ProquintEncoder, Quintable};
+///
+/// let foo: u32 = 12;
+/// let encoder = ProquintEncoder::new(
+///     ['B', 'D', 'F', 'G', 'H', 'J', 'K', 'L', 'M', 'N', 'P', 'R', 'S', 'T', 'V', 'Z'],
+///     ['A', 'I', 'O', 'U'],
+///     '_',
+/// ).unwrap();
+///
+/// let quint = encoder.encode(&foo);
+/// assert_eq!(quint, foo.to_quint().to_uppercase().replace('-', "_"));
+/// assert_eq!(encoder.decode::<u32>(&quint).unwrap(), foo);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProquintEncoder {
+    consonants: [char; 16],
+    vowels: [char; 4],
+    separator: char,
+}
+
+impl Default for ProquintEncoder {
+    fn default() -> Self {
+        let mut consonants = ['\0'; 16];
+        consonants.copy_from_slice(UINT2CONSONANT);
+        let mut vowels = ['\0'; 4];
+        vowels.copy_from_slice(UINT2VOWEL);
+
+        ProquintEncoder {
+            consonants,
+            vowels,
+            separator: SEPARATOR,
+        }
+    }
+}
+
+impl ProquintEncoder {
+    /// Builds an encoder from a custom consonant table, vowel table, and
+    /// separator.
+    ///
+    /// Returns `QuintError::InputInvalid` unless all 16 consonants, all 4
+    /// vowels, and the separator are pairwise distinct from one another —
+    /// otherwise a character meant as one symbol could be misread as
+    /// another while translating to and from the default alphabet.
+    pub fn new(consonants: [char; 16], vowels: [char; 4], separator: char) -> Result<Self, QuintError> {
+        let mut all: Vec<char> = consonants.to_vec();
+        all.extend_from_slice(&vowels);
+        all.push(separator);
+
+        let mut sorted = all.clone();
+        sorted.sort();
+        sorted.dedup();
+        if sorted.len() != all.len() {
+            return Err(QuintError::InputInvalid);
+        }
+
+        Ok(ProquintEncoder {
+            consonants,
+            vowels,
+            separator,
+        })
+    }
+
+    /// Encodes `value` using this encoder's alphabet and separator.
+    pub fn encode<T: Quintable>(&self, value: &T) -> String {
+        self.translate_from_default(&value.to_quint())
+    }
+
+    /// Decodes `quint`, which was produced by this encoder's alphabet and
+    /// separator, back to `T`.
+    pub fn decode<T: Quintable>(&self, quint: &str) -> Result<T, QuintError> {
+        T::from_quint(&self.translate_to_default(quint)?)
+    }
+
+    fn translate_from_default(&self, quint: &str) -> String {
+        quint.chars()
+            .map(|c| {
+                if c == SEPARATOR {
+                    return self.separator;
+                }
+                if let Some(i) = UINT2CONSONANT.iter().position(|&x| x == c) {
+                    return self.consonants[i];
+                }
+                if let Some(i) = UINT2VOWEL.iter().position(|&x| x == c) {
+                    return self.vowels[i];
+                }
+                c
+            })
+            .collect()
+    }
+
+    fn translate_to_default(&self, quint: &str) -> Result<String, QuintError> {
+        let mut out = String::with_capacity(quint.len());
+        for c in quint.chars() {
+            if c == self.separator {
+                out.push(SEPARATOR);
+                continue;
+            }
+            if let Some(i) = self.consonants.iter().position(|&x| x == c) {
+                out.push(UINT2CONSONANT[i]);
+                continue;
+            }
+            if let Some(i) = self.vowels.iter().position(|&x| x == c) {
+                out.push(UINT2VOWEL[i]);
+                continue;
+            }
+            return Err(QuintError::InputInvalid);
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use std::net::{Ipv4Addr, Ipv6Addr};
     use Quintable;
+    use TryQuintable;
     use QuintError;
+    use ProquintEncoder;
+    use quickcheck::TestResult;
 
     #[test]
     fn quint_too_small() {
         assert_eq!(u16::from_quint("lub").err(), Some(QuintError::InputTooSmall));
         assert_eq!(u32::from_quint("lubab").err(), Some(QuintError::InputTooSmall));
         assert_eq!(u64::from_quint("lubab-gutuz").err(), Some(QuintError::InputTooSmall));
+        assert_eq!(u128::from_quint("lubab-gutuz-kobim").err(), Some(QuintError::InputTooSmall));
     }
 
     #[test]
@@ -363,6 +876,7 @@ mod tests {
         assert_eq!(u16::from_quint("lubab-gutuz").err(), Some(QuintError::InputTooLarge));
         assert_eq!(u32::from_quint("lubab-gutuz-kobim").err(), Some(QuintError::InputTooLarge));
         assert_eq!(u64::from_quint("lubab-gutuz-kobim-fival-bison").err(), Some(QuintError::InputTooLarge));
+        assert_eq!(u128::from_quint("lubab-gutuz-kobim-fival-bison-lubab-gutuz-kobim-fival").err(), Some(QuintError::InputTooLarge));
     }
 
     fn ipv4_test(ipv4: [u8; 4], quint: &str) {
@@ -432,6 +946,12 @@ mod tests {
         }
     }
 
+    quickcheck! {
+        fn u128(xs: u128) -> bool {
+            back_and_forth(xs)
+        }
+    }
+
     quickcheck! {
         fn ipv4(xs: Ipv4Addr) -> bool {
             back_and_forth(xs)
@@ -444,4 +964,144 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bytes_even_length_round_trips() {
+        let bytes: Vec<u8> = vec![0xCA, 0xFE, 0xBA, 0xBE];
+        let quint = bytes.try_to_quint().unwrap();
+        assert_eq!(Vec::<u8>::try_from_quint(&quint).unwrap(), bytes);
+    }
+
+    #[test]
+    fn bytes_odd_length_is_rejected() {
+        let bytes: Vec<u8> = vec![0xCA, 0xFE, 0xBA];
+        assert_eq!(bytes.try_to_quint().err(), Some(QuintError::InputInvalid));
+    }
+
+    quickcheck! {
+        fn bytes_even_length(xs: Vec<u8>) -> TestResult {
+            if !xs.len().is_multiple_of(2) {
+                return TestResult::discard();
+            }
+            let quint = match xs.try_to_quint() {
+                Ok(q) => q,
+                Err(_) => return TestResult::failed(),
+            };
+            TestResult::from_bool(Vec::<u8>::try_from_quint(&quint).as_ref() == Ok(&xs))
+        }
+    }
+
+    #[test]
+    fn strict_accepts_valid_quints() {
+        assert_eq!(u32::from_quint_strict("rotab-vinat").unwrap(), 3141592653u32);
+        assert_eq!(Ipv4Addr::from_quint_strict("lusab-babad").unwrap(), Ipv4Addr::new(127, 0, 0, 1));
+    }
+
+    #[test]
+    fn strict_rejects_unknown_characters() {
+        assert_eq!(u32::from_quint_strict("rotab!vinat").err(), Some(QuintError::InputInvalid));
+        assert_eq!(u16::from_quint_strict("lusax").err(), Some(QuintError::InputInvalid));
+    }
+
+    #[test]
+    fn strict_rejects_misplaced_separator() {
+        assert_eq!(u32::from_quint_strict("rota-bvinat").err(), Some(QuintError::InputInvalid));
+    }
+
+    #[test]
+    fn strict_rejects_dangling_separator() {
+        assert_eq!(u16::from_quint_strict("lusab-").err(), Some(QuintError::InputInvalid));
+    }
+
+    #[test]
+    fn strict_rejects_broken_alternation() {
+        // a vowel where a consonant is expected
+        assert_eq!(u16::from_quint_strict("aabab").err(), Some(QuintError::InputInvalid));
+    }
+
+    #[test]
+    fn lenient_ignores_what_strict_rejects() {
+        assert!(u32::from_quint("rotab!vinat").is_ok());
+        assert!(u32::from_quint_strict("rotab!vinat").is_err());
+    }
+
+    #[test]
+    fn checked_round_trips() {
+        let foo: u32 = 3141592653;
+        let checked = foo.to_quint_checked();
+        assert_eq!(u32::from_quint_checked(&checked).unwrap(), foo);
+    }
+
+    #[test]
+    fn checked_detects_corruption() {
+        let foo: u32 = 3141592653;
+        let mut checked = foo.to_quint_checked();
+        checked.replace_range(0..1, "z");
+        assert_eq!(u32::from_quint_checked(&checked).err(), Some(QuintError::ChecksumMismatch));
+    }
+
+    quickcheck! {
+        fn checked_u32(xs: u32) -> bool {
+            let checked = xs.to_quint_checked();
+            u32::from_quint_checked(&checked) == Ok(xs)
+        }
+    }
+
+    #[test]
+    fn encoder_default_matches_quintable() {
+        let foo: u32 = 12;
+        let encoder = ProquintEncoder::default();
+        assert_eq!(encoder.encode(&foo), foo.to_quint());
+        assert_eq!(encoder.decode::<u32>(&foo.to_quint()).unwrap(), foo);
+    }
+
+    #[test]
+    fn encoder_custom_separator_round_trips() {
+        let encoder = ProquintEncoder::new(
+            ['b', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'r', 's', 't', 'v', 'z'],
+            ['a', 'i', 'o', 'u'],
+            ' ',
+        ).unwrap();
+
+        let ip = Ipv4Addr::new(127, 0, 0, 1);
+        let quint = encoder.encode(&ip);
+        assert_eq!(quint, "lusab babad");
+        assert_eq!(encoder.decode::<Ipv4Addr>(&quint).unwrap(), ip);
+    }
+
+    #[test]
+    fn encoder_rejects_duplicate_consonants() {
+        let err = ProquintEncoder::new(
+            ['b', 'b', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'r', 's', 't', 'v', 'z'],
+            ['a', 'i', 'o', 'u'],
+            '-',
+        ).err();
+        assert_eq!(err, Some(QuintError::InputInvalid));
+    }
+
+    #[test]
+    fn encoder_rejects_duplicate_vowels() {
+        let err = ProquintEncoder::new(
+            ['b', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'r', 's', 't', 'v', 'z'],
+            ['a', 'a', 'o', 'u'],
+            '-',
+        ).err();
+        assert_eq!(err, Some(QuintError::InputInvalid));
+    }
+
+    #[test]
+    fn encoder_rejects_separator_reused_as_vowel() {
+        let err = ProquintEncoder::new(
+            ['b', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'r', 's', 't', 'v', 'z'],
+            ['a', 'i', 'o', 'u'],
+            'a',
+        ).err();
+        assert_eq!(err, Some(QuintError::InputInvalid));
+    }
+
+    #[test]
+    fn encoder_decode_rejects_unknown_character() {
+        let encoder = ProquintEncoder::default();
+        assert_eq!(encoder.decode::<u32>("rotab!vinat").err(), Some(QuintError::InputInvalid));
+    }
+
 }